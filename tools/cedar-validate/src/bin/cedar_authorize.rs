@@ -0,0 +1,206 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead};
+
+use cedar_policy::{Authorizer, Context, Decision, Entities, EntityUid, PolicySet, Request};
+use serde::Deserialize;
+use serde_json::json;
+
+use cedar_validate::discovery::discover_policy_files;
+use cedar_validate::error::RajaError;
+
+fn empty_context() -> serde_json::Value {
+    json!({})
+}
+
+#[derive(Deserialize)]
+struct AuthRequest {
+    principal: String,
+    action: String,
+    resource: String,
+    #[serde(default = "empty_context")]
+    context: serde_json::Value,
+}
+
+fn load_policy_set(roots: &[String]) -> Result<PolicySet, RajaError> {
+    let (policy_files, _schema) = discover_policy_files(roots)?;
+    let mut combined = String::new();
+    for path in &policy_files {
+        let content = fs::read_to_string(path)?;
+        combined.push_str(&content);
+        if !content.ends_with('\n') {
+            combined.push('\n');
+        }
+    }
+    if combined.trim().is_empty() {
+        return Err(RajaError::EmptyInput);
+    }
+    combined
+        .parse::<PolicySet>()
+        .map_err(|err| RajaError::Parse(err.to_string()))
+}
+
+fn load_entities(path: &str) -> Result<Entities, RajaError> {
+    let entities_src = fs::read_to_string(path)?;
+    Entities::from_json_str(&entities_src, None)
+        .map_err(|err| RajaError::Parse(format!("entities: {err}")))
+}
+
+fn build_request(req: &AuthRequest) -> Result<Request, RajaError> {
+    let principal: EntityUid = req
+        .principal
+        .parse()
+        .map_err(|err| RajaError::Parse(format!("principal: {err}")))?;
+    let action: EntityUid = req
+        .action
+        .parse()
+        .map_err(|err| RajaError::Parse(format!("action: {err}")))?;
+    let resource: EntityUid = req
+        .resource
+        .parse()
+        .map_err(|err| RajaError::Parse(format!("resource: {err}")))?;
+    let context = Context::from_json_value(req.context.clone(), None)
+        .map_err(|err| RajaError::Parse(format!("context: {err}")))?;
+    Request::new(Some(principal), Some(action), Some(resource), context, None)
+        .map_err(|err| RajaError::Parse(format!("request: {err}")))
+}
+
+/// Evaluates a single batch line against the policy set and entities,
+/// printing the decision, determining policy IDs, and any errors as JSON.
+/// A line that fails to parse as JSON or as a request is reported the same
+/// way an evaluation error is, rather than aborting the rest of the batch.
+/// Returns `true` if the line evaluated without errors.
+fn evaluate_line(
+    authorizer: &Authorizer,
+    policy_set: &PolicySet,
+    entities: &Entities,
+    line: &str,
+) -> bool {
+    let req = serde_json::from_str::<AuthRequest>(line)
+        .map_err(|err| RajaError::Parse(format!("request json: {err}")))
+        .and_then(|req| build_request(&req));
+
+    let request = match req {
+        Ok(request) => request,
+        Err(err) => {
+            println!("{}", json!({ "decision": null, "reasons": [], "errors": [err.to_string()] }));
+            return false;
+        }
+    };
+
+    let response = authorizer.is_authorized(&request, policy_set, entities);
+
+    let reasons: Vec<String> = response.diagnostics().reason().map(|id| id.to_string()).collect();
+    let errors: Vec<String> = response
+        .diagnostics()
+        .errors()
+        .map(|err| err.to_string())
+        .collect();
+    let decision = matches!(response.decision(), Decision::Allow);
+    let ok = errors.is_empty();
+
+    println!(
+        "{}",
+        json!({
+            "decision": if decision { "Allow" } else { "Deny" },
+            "reasons": reasons,
+            "errors": errors,
+        })
+    );
+
+    ok
+}
+
+fn run() -> Result<(), RajaError> {
+    let mut policy_roots = Vec::new();
+    let mut entities_path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--entities" {
+            entities_path = args.next();
+        } else {
+            policy_roots.push(arg);
+        }
+    }
+    let entities_path = entities_path.unwrap_or_else(|| "entities.json".to_string());
+    if policy_roots.is_empty() {
+        policy_roots.push("policies".to_string());
+    }
+
+    let policy_set = load_policy_set(&policy_roots)?;
+    let entities = load_entities(&entities_path)?;
+    let authorizer = Authorizer::new();
+
+    let stdin = io::stdin();
+    let mut any_errors = false;
+    for line in stdin.lock().lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !evaluate_line(&authorizer, &policy_set, &entities, &line) {
+            any_errors = true;
+        }
+    }
+
+    if any_errors {
+        return Err(RajaError::Validation);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(err.code());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (PolicySet, Entities) {
+        let policy_set = r#"permit(principal == User::"alice", action == Action::"view", resource == Photo::"1");"#
+            .parse::<PolicySet>()
+            .unwrap();
+        let entities = Entities::from_json_str("[]", None).unwrap();
+        (policy_set, entities)
+    }
+
+    #[test]
+    fn malformed_json_line_is_reported_without_panicking() {
+        let (policy_set, entities) = fixture();
+        let authorizer = Authorizer::new();
+
+        let ok = evaluate_line(&authorizer, &policy_set, &entities, "not json");
+
+        assert!(!ok);
+    }
+
+    #[test]
+    fn unparseable_entity_uid_is_reported_without_panicking() {
+        let (policy_set, entities) = fixture();
+        let authorizer = Authorizer::new();
+        let line = r#"{"principal":"not-a-euid","action":"Action::\"view\"","resource":"Photo::\"1\""}"#;
+
+        let ok = evaluate_line(&authorizer, &policy_set, &entities, line);
+
+        assert!(!ok);
+    }
+
+    #[test]
+    fn valid_request_evaluates_to_allow() {
+        let (policy_set, entities) = fixture();
+        let authorizer = Authorizer::new();
+        let line = r#"{"principal":"User::\"alice\"","action":"Action::\"view\"","resource":"Photo::\"1\""}"#;
+
+        let ok = evaluate_line(&authorizer, &policy_set, &entities, line);
+
+        assert!(ok);
+        let request = build_request(&serde_json::from_str::<AuthRequest>(line).unwrap()).unwrap();
+        let response = authorizer.is_authorized(&request, &policy_set, &entities);
+        assert!(matches!(response.decision(), Decision::Allow));
+    }
+}
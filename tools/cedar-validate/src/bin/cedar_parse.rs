@@ -1,42 +1,91 @@
 use std::io::{self, Read};
 
-use cedar_policy::Policy;
+use cedar_policy::PolicySet;
+use serde_json::{Map, Value};
 
-fn main() {
+use cedar_validate::error::RajaError;
+
+fn policy_set_to_json(policy_src: &str) -> Result<Value, RajaError> {
+    let policy_src = policy_src.trim();
+    if policy_src.is_empty() {
+        return Err(RajaError::EmptyInput);
+    }
+
+    let policy_set = policy_src
+        .parse::<PolicySet>()
+        .map_err(|err| RajaError::Parse(err.to_string()))?;
+
+    let mut policies = Map::new();
+    for policy in policy_set.policies() {
+        let json = policy
+            .to_json()
+            .map_err(|err| RajaError::Serialize(format!("policy {}: {err}", policy.id())))?;
+        policies.insert(policy.id().to_string(), json);
+    }
+
+    let mut templates = Map::new();
+    for template in policy_set.templates() {
+        let json = template
+            .to_json()
+            .map_err(|err| RajaError::Serialize(format!("template {}: {err}", template.id())))?;
+        templates.insert(template.id().to_string(), json);
+    }
+
+    Ok(Value::Object(Map::from_iter([
+        ("policies".to_string(), Value::Object(policies)),
+        ("templates".to_string(), Value::Object(templates)),
+    ])))
+}
+
+fn run() -> Result<(), RajaError> {
     let mut input = String::new();
-    if io::stdin().read_to_string(&mut input).is_err() {
-        eprintln!("failed to read policy from stdin");
-        std::process::exit(2);
+    io::stdin().read_to_string(&mut input)?;
+
+    let output = policy_set_to_json(&input)?;
+    let encoded =
+        serde_json::to_string(&output).map_err(|err| RajaError::Serialize(err.to_string()))?;
+    println!("{encoded}");
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(err.code());
     }
-    let policy_src = input.trim();
-    if policy_src.is_empty() {
-        eprintln!("policy input was empty");
-        std::process::exit(3);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_static_policies_and_templates_by_id() {
+        let src = r#"
+permit(principal, action, resource);
+
+@id("tpl")
+permit(principal == ?principal, action, resource);
+"#;
+
+        let output = policy_set_to_json(src).unwrap();
+
+        let policies = output["policies"].as_object().unwrap();
+        let templates = output["templates"].as_object().unwrap();
+        assert_eq!(policies.len(), 1);
+        assert_eq!(templates.len(), 1);
+        assert!(templates.contains_key("policy1"));
+        assert!(policies.contains_key("policy0"));
+    }
+
+    #[test]
+    fn empty_input_is_an_error() {
+        assert!(policy_set_to_json("   ").is_err());
     }
 
-    let policy = match Policy::parse(None, policy_src) {
-        Ok(policy) => policy,
-        Err(err) => {
-            eprintln!("failed to parse policy: {err}");
-            std::process::exit(1);
-        }
-    };
-
-    let json = match policy.to_json() {
-        Ok(json) => json,
-        Err(err) => {
-            eprintln!("failed to serialize policy to json: {err}");
-            std::process::exit(4);
-        }
-    };
-
-    match serde_json::to_string(&json) {
-        Ok(output) => {
-            println!("{output}");
-        }
-        Err(err) => {
-            eprintln!("failed to encode policy json: {err}");
-            std::process::exit(5);
-        }
+    #[test]
+    fn malformed_policy_is_an_error() {
+        assert!(policy_set_to_json("not a policy").is_err());
     }
 }
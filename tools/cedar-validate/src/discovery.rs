@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::error::RajaError;
+
+fn is_schema_file(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()) == Some("schema.cedar")
+}
+
+fn is_cedar_file(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()) == Some("cedar")
+}
+
+/// Walks every input path, recursing into directories, and returns the
+/// discovered `.cedar` files (schema excluded) alongside the single
+/// `schema.cedar`, if any. Errors if more than one distinct `schema.cedar`
+/// is found, since validating every policy against an arbitrary one of them
+/// would depend on directory walk order.
+pub fn discover_policy_files(
+    roots: &[String],
+) -> Result<(Vec<PathBuf>, Option<PathBuf>), RajaError> {
+    let mut policies = Vec::new();
+    let mut schemas = Vec::new();
+
+    for root in roots {
+        let root_path = Path::new(root);
+        if root_path.is_dir() {
+            for entry in WalkDir::new(root_path)
+                .into_iter()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.file_type().is_file())
+            {
+                let path = entry.into_path();
+                if !is_cedar_file(&path) {
+                    continue;
+                }
+                if is_schema_file(&path) {
+                    schemas.push(path);
+                } else {
+                    policies.push(path);
+                }
+            }
+        } else if is_schema_file(root_path) {
+            schemas.push(root_path.to_path_buf());
+        } else {
+            policies.push(root_path.to_path_buf());
+        }
+    }
+
+    policies.sort();
+    schemas.sort();
+    schemas.dedup();
+
+    let schema = match schemas.len() {
+        0 => None,
+        1 => schemas.pop(),
+        _ => {
+            let paths = schemas
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(RajaError::SchemaLoad(format!(
+                "multiple schema.cedar files found, expected at most one: {paths}"
+            )));
+        }
+    };
+
+    Ok((policies, schema))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write(dir: &Path, relative: &str, content: &str) {
+        let path = dir.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn discovers_nested_policies_sorted_and_separates_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "teamb/b.cedar", "permit(principal, action, resource);");
+        write(dir.path(), "teama/a.cedar", "permit(principal, action, resource);");
+        write(dir.path(), "schema.cedar", "");
+        write(dir.path(), "README.md", "not a policy");
+
+        let (policies, schema) =
+            discover_policy_files(&[dir.path().to_string_lossy().into_owned()]).unwrap();
+
+        assert_eq!(policies.len(), 2);
+        assert!(policies[0] < policies[1]);
+        assert_eq!(schema, Some(dir.path().join("schema.cedar")));
+    }
+
+    #[test]
+    fn errors_on_multiple_distinct_schemas() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "teama/schema.cedar", "entity A;");
+        write(dir.path(), "teamb/schema.cedar", "entity B;");
+
+        let result = discover_policy_files(&[dir.path().to_string_lossy().into_owned()]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn same_schema_reachable_from_two_roots_is_not_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "schema.cedar", "entity A;");
+        let root = dir.path().to_string_lossy().into_owned();
+        let schema_path = dir.path().join("schema.cedar").to_string_lossy().into_owned();
+
+        let (_, schema) = discover_policy_files(&[root, schema_path]).unwrap();
+
+        assert_eq!(schema, Some(dir.path().join("schema.cedar")));
+    }
+}
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RajaError {
+    #[error("failed to read input: {0}")]
+    ReadInput(#[from] std::io::Error),
+
+    #[error("policy input was empty")]
+    EmptyInput,
+
+    #[error("failed to parse policies: {0}")]
+    Parse(String),
+
+    #[error("failed to serialize policy json: {0}")]
+    Serialize(String),
+
+    #[error("failed to load schema: {0}")]
+    SchemaLoad(String),
+
+    #[error("policy set failed validation")]
+    Validation,
+
+    #[error("failed to pull policies from registry: {0}")]
+    OciPull(String),
+}
+
+impl RajaError {
+    /// Process exit code for this error: `Parse` 1, `ReadInput` 2,
+    /// `EmptyInput` 3, `Serialize` 4, `SchemaLoad` 5, `Validation` 6,
+    /// `OciPull` 7.
+    pub fn code(&self) -> i32 {
+        match self {
+            RajaError::ReadInput(_) => 2,
+            RajaError::EmptyInput => 3,
+            RajaError::Parse(_) => 1,
+            RajaError::Serialize(_) => 4,
+            RajaError::SchemaLoad(_) => 5,
+            RajaError::Validation => 6,
+            RajaError::OciPull(_) => 7,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_variant_maps_to_its_documented_exit_code() {
+        assert_eq!(RajaError::Parse(String::new()).code(), 1);
+        assert_eq!(
+            RajaError::ReadInput(std::io::Error::from(std::io::ErrorKind::NotFound)).code(),
+            2
+        );
+        assert_eq!(RajaError::EmptyInput.code(), 3);
+        assert_eq!(RajaError::Serialize(String::new()).code(), 4);
+        assert_eq!(RajaError::SchemaLoad(String::new()).code(), 5);
+        assert_eq!(RajaError::Validation.code(), 6);
+        assert_eq!(RajaError::OciPull(String::new()).code(), 7);
+    }
+}
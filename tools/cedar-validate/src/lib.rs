@@ -0,0 +1,3 @@
+pub mod discovery;
+pub mod error;
+pub mod oci;
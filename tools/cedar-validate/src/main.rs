@@ -1,37 +1,35 @@
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::str::FromStr;
 
-use cedar_policy::PolicySet;
-use glob::glob;
+use cedar_policy::{PolicySet, Schema, ValidationMode, Validator};
 
-fn main() {
-    let policy_dir = env::args().nth(1).unwrap_or_else(|| "policies".to_string());
-    let policy_root = Path::new(&policy_dir);
-    if !policy_root.is_dir() {
-        eprintln!("policy directory not found: {}", policy_root.display());
-        std::process::exit(2);
-    }
+use cedar_validate::discovery::discover_policy_files;
+use cedar_validate::error::RajaError;
+use cedar_validate::oci;
 
-    let mut combined = String::new();
-    let pattern = policy_root.join("*.cedar");
-    let pattern_str = pattern
-        .to_str()
-        .expect("policy directory path should be valid utf-8");
-
-    for entry in glob(pattern_str).expect("failed to read policy glob pattern") {
-        let path = match entry {
-            Ok(path) => path,
-            Err(err) => {
-                eprintln!("failed to resolve policy file: {err}");
-                std::process::exit(3);
+fn validate(
+    roots: &[String],
+    permissive: bool,
+    docker_config_json_path: Option<&str>,
+) -> Result<(), RajaError> {
+    let roots: Vec<String> = roots
+        .iter()
+        .map(|root| {
+            if root.starts_with("oci://") {
+                oci::pull_policies(root, docker_config_json_path)
+                    .map(|dir| dir.to_string_lossy().into_owned())
+            } else {
+                Ok(root.clone())
             }
-        };
-        if path.file_name().and_then(|name| name.to_str()) == Some("schema.cedar") {
-            continue;
-        }
-        let content = fs::read_to_string(&path)
-            .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+        })
+        .collect::<Result<_, RajaError>>()?;
+
+    let (policy_files, schema_path) = discover_policy_files(&roots)?;
+
+    let mut combined = String::new();
+    for path in &policy_files {
+        let content = fs::read_to_string(path)?;
         combined.push_str(&content);
         if !content.ends_with('\n') {
             combined.push('\n');
@@ -39,12 +37,136 @@ fn main() {
     }
 
     if combined.trim().is_empty() {
-        eprintln!("no policies found in {}", policy_root.display());
-        std::process::exit(4);
+        eprintln!("no policy files found in {}", roots.join(", "));
+        return Err(RajaError::EmptyInput);
+    }
+
+    let policy_set = combined
+        .parse::<PolicySet>()
+        .map_err(|err| RajaError::Parse(err.to_string()))?;
+
+    let Some(schema_path) = schema_path else {
+        return Ok(());
+    };
+
+    let schema_src = fs::read_to_string(&schema_path)?;
+    let schema = Schema::from_str(&schema_src).map_err(|err| RajaError::SchemaLoad(err.to_string()))?;
+
+    let mode = if permissive {
+        ValidationMode::Permissive
+    } else {
+        ValidationMode::Strict
+    };
+    let result = Validator::new(schema).validate(&policy_set, mode);
+
+    for warning in result.validation_warnings() {
+        eprintln!("[{}] warning: {warning}", warning.location().policy_id());
+    }
+    for error in result.validation_errors() {
+        eprintln!("[{}] error: {error}", error.location().policy_id());
+    }
+
+    if !result.validation_passed() {
+        return Err(RajaError::Validation);
+    }
+
+    Ok(())
+}
+
+fn run() -> Result<(), RajaError> {
+    let mut roots = Vec::new();
+    let mut permissive = false;
+    let mut docker_config_json_path = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--permissive" {
+            permissive = true;
+        } else if arg == "--docker-config-json-path" {
+            docker_config_json_path = args.next();
+        } else {
+            roots.push(arg);
+        }
+    }
+    if roots.is_empty() {
+        roots.push("policies".to_string());
     }
 
-    if let Err(err) = combined.parse::<PolicySet>() {
-        eprintln!("failed to parse policies with cedar-policy: {err}");
-        std::process::exit(1);
+    validate(&roots, permissive, docker_config_json_path.as_deref())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(err.code());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const SCHEMA: &str = r#"
+{
+  "": {
+    "entityTypes": { "User": {}, "Resource": {} },
+    "actions": {
+      "view": {
+        "appliesTo": {
+          "principalTypes": ["User"],
+          "resourceTypes": ["Resource"]
+        }
+      }
+    }
+  }
+}
+"#;
+
+    fn write(dir: &Path, relative: &str, content: &str) {
+        fs::write(dir.join(relative), content).unwrap();
+    }
+
+    #[test]
+    fn missing_schema_falls_back_to_syntax_only() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "a.cedar",
+            r#"permit(principal, action, resource);"#,
+        );
+
+        let result = validate(&[dir.path().to_string_lossy().into_owned()], false, None);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn strict_mode_rejects_what_permissive_mode_allows() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "schema.cedar", SCHEMA);
+        write(
+            dir.path(),
+            "a.cedar",
+            r#"permit(principal, action == Action::"view", resource) when { [].contains(1) };"#,
+        );
+        let root = dir.path().to_string_lossy().into_owned();
+
+        assert!(validate(std::slice::from_ref(&root), false, None).is_err());
+        assert!(validate(&[root], true, None).is_ok());
+    }
+
+    #[test]
+    fn schema_present_rejects_invalid_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "schema.cedar", SCHEMA);
+        write(
+            dir.path(),
+            "a.cedar",
+            r#"permit(principal, action == Action::"view", resource) when { resource.nonexistent };"#,
+        );
+
+        let result = validate(&[dir.path().to_string_lossy().into_owned()], false, None);
+
+        assert!(result.is_err());
     }
 }
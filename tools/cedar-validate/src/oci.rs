@@ -0,0 +1,161 @@
+use std::fs;
+use std::path::PathBuf;
+
+use base64::Engine;
+use flate2::read::GzDecoder;
+use oci_distribution::client::{Client, ClientConfig};
+use oci_distribution::secrets::RegistryAuth;
+use oci_distribution::Reference;
+use serde::Deserialize;
+
+use crate::error::RajaError;
+
+#[derive(Deserialize)]
+struct DockerConfig {
+    auths: std::collections::HashMap<String, DockerConfigAuth>,
+}
+
+#[derive(Deserialize)]
+struct DockerConfigAuth {
+    auth: String,
+}
+
+fn registry_auth(registry: &str, docker_config_json_path: Option<&str>) -> RegistryAuth {
+    let Some(path) = docker_config_json_path else {
+        return RegistryAuth::Anonymous;
+    };
+    let Ok(contents) = fs::read_to_string(path) else {
+        return RegistryAuth::Anonymous;
+    };
+    let Ok(config) = serde_json::from_str::<DockerConfig>(&contents) else {
+        return RegistryAuth::Anonymous;
+    };
+    let Some(entry) = config.auths.get(registry) else {
+        return RegistryAuth::Anonymous;
+    };
+    let Ok(decoded) = base64::engine::general_purpose::STANDARD.decode(&entry.auth) else {
+        return RegistryAuth::Anonymous;
+    };
+    let Ok(decoded) = String::from_utf8(decoded) else {
+        return RegistryAuth::Anonymous;
+    };
+    match decoded.split_once(':') {
+        Some((username, password)) => {
+            RegistryAuth::Basic(username.to_string(), password.to_string())
+        }
+        None => RegistryAuth::Anonymous,
+    }
+}
+
+pub fn pull_policies(
+    image_reference: &str,
+    docker_config_json_path: Option<&str>,
+) -> Result<PathBuf, RajaError> {
+    let image_reference = image_reference
+        .strip_prefix("oci://")
+        .unwrap_or(image_reference);
+    let reference: Reference = image_reference
+        .parse()
+        .map_err(|err| RajaError::OciPull(format!("invalid reference {image_reference}: {err}")))?;
+
+    let cache_dir = std::env::temp_dir()
+        .join("cedar-validate-cache")
+        .join(sanitize(image_reference));
+    // Clear any previous pull so a mutable tag that dropped or renamed a
+    // `.cedar` file doesn't leave the old one behind for discovery to find.
+    if let Err(err) = fs::remove_dir_all(&cache_dir) {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            return Err(RajaError::OciPull(format!(
+                "clearing stale cache dir: {err}"
+            )));
+        }
+    }
+    fs::create_dir_all(&cache_dir)
+        .map_err(|err| RajaError::OciPull(format!("creating cache dir: {err}")))?;
+
+    let auth = registry_auth(reference.registry(), docker_config_json_path);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| RajaError::OciPull(format!("starting async runtime: {err}")))?;
+
+    runtime.block_on(async {
+        let client = Client::new(ClientConfig::default());
+        let accepted_media_types = vec!["application/vnd.oci.image.layer.v1.tar+gzip"];
+        let image = client
+            .pull(&reference, &auth, accepted_media_types)
+            .await
+            .map_err(|err| RajaError::OciPull(format!("pulling {image_reference}: {err}")))?;
+
+        for layer in image.layers {
+            let decoder = GzDecoder::new(&layer.data[..]);
+            let mut archive = tar::Archive::new(decoder);
+            archive
+                .unpack(&cache_dir)
+                .map_err(|err| RajaError::OciPull(format!("unpacking layer: {err}")))?;
+        }
+
+        Ok::<(), RajaError>(())
+    })?;
+
+    Ok(cache_dir)
+}
+
+fn sanitize(reference: &str) -> String {
+    reference
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn sanitize_escapes_path_separators() {
+        assert_eq!(
+            sanitize("registry.example.com/org/policies:latest"),
+            "registry_example_com_org_policies_latest"
+        );
+        assert!(!sanitize("../../etc/passwd").contains('/'));
+    }
+
+    #[test]
+    fn registry_auth_is_anonymous_without_config_path() {
+        assert!(matches!(registry_auth("registry.example.com", None), RegistryAuth::Anonymous));
+    }
+
+    #[test]
+    fn registry_auth_decodes_basic_credentials() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode("alice:s3cret");
+        write!(
+            file,
+            r#"{{"auths":{{"registry.example.com":{{"auth":"{encoded}"}}}}}}"#
+        )
+        .unwrap();
+
+        let auth = registry_auth("registry.example.com", Some(file.path().to_str().unwrap()));
+
+        match auth {
+            RegistryAuth::Basic(username, password) => {
+                assert_eq!(username, "alice");
+                assert_eq!(password, "s3cret");
+            }
+            RegistryAuth::Anonymous => panic!("expected basic auth"),
+        }
+    }
+
+    #[test]
+    fn registry_auth_is_anonymous_for_unknown_registry() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"auths":{{}}}}"#).unwrap();
+
+        let auth = registry_auth("registry.example.com", Some(file.path().to_str().unwrap()));
+
+        assert!(matches!(auth, RegistryAuth::Anonymous));
+    }
+}